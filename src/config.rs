@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::path::PathBuf;
+
+use cosmic_config::{Config as CosmicConfig, ConfigGet, ConfigSet as CosmicConfigSet};
+use serde::{Deserialize, Serialize};
+
+/// Bumped whenever a field is added, removed, or changes meaning.
+pub const CONFIG_VERSION: u64 = 1;
+
+/// Maximum number of recently-opened project folders to remember.
+const MAX_RECENT_PROJECTS: usize = 10;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AppTheme {
+    Dark,
+    Light,
+    #[default]
+    System,
+}
+
+/// Persisted application settings, backed by `cosmic-config`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub wrap: bool,
+    pub font_name: String,
+    pub font_size: u16,
+    pub tab_width: u16,
+    pub vim_bindings: bool,
+    pub app_theme: AppTheme,
+    pub recent_projects: Vec<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            wrap: false,
+            font_name: "Fira Mono".to_string(),
+            font_size: 14,
+            tab_width: 4,
+            vim_bindings: false,
+            app_theme: AppTheme::System,
+            recent_projects: Vec::new(),
+        }
+    }
+}
+
+/// A single settable field, used by `Message::ConfigSet` so the UI does not
+/// need one message variant per field (mirrors the old, single-purpose
+/// `Message::Wrap`).
+#[derive(Clone, Debug)]
+pub enum ConfigSet {
+    Wrap(bool),
+    FontName(String),
+    FontSize(u16),
+    TabWidth(u16),
+    VimBindings(bool),
+    AppTheme(AppTheme),
+}
+
+impl Config {
+    /// Open (creating if necessary) the `cosmic-config` handle for this app.
+    pub fn config_handler() -> Option<CosmicConfig> {
+        match CosmicConfig::new(crate::App::APP_ID, CONFIG_VERSION) {
+            Ok(config_handler) => Some(config_handler),
+            Err(err) => {
+                log::error!("failed to create cosmic-config handler: {err}");
+                None
+            }
+        }
+    }
+
+    /// Load settings from `config_handler`, falling back to defaults for any
+    /// field that is missing or fails to parse.
+    pub fn load(config_handler: &CosmicConfig) -> Self {
+        let default = Self::default();
+        Self {
+            wrap: config_handler.get("wrap").unwrap_or(default.wrap),
+            font_name: config_handler
+                .get("font_name")
+                .unwrap_or(default.font_name),
+            font_size: config_handler
+                .get("font_size")
+                .unwrap_or(default.font_size),
+            tab_width: config_handler
+                .get("tab_width")
+                .unwrap_or(default.tab_width),
+            vim_bindings: config_handler
+                .get("vim_bindings")
+                .unwrap_or(default.vim_bindings),
+            app_theme: config_handler
+                .get("app_theme")
+                .unwrap_or(default.app_theme),
+            recent_projects: config_handler
+                .get("recent_projects")
+                .unwrap_or(default.recent_projects),
+        }
+    }
+
+    /// Apply one field change in memory.
+    pub fn apply(&mut self, set: ConfigSet) {
+        match set {
+            ConfigSet::Wrap(wrap) => self.wrap = wrap,
+            ConfigSet::FontName(font_name) => self.font_name = font_name,
+            ConfigSet::FontSize(font_size) => self.font_size = font_size,
+            ConfigSet::TabWidth(tab_width) => self.tab_width = tab_width,
+            ConfigSet::VimBindings(vim_bindings) => self.vim_bindings = vim_bindings,
+            ConfigSet::AppTheme(app_theme) => self.app_theme = app_theme,
+        }
+    }
+
+    /// Apply one field change and persist it to `config_handler`.
+    pub fn set(&mut self, config_handler: &CosmicConfig, set: ConfigSet) {
+        self.apply(set.clone());
+
+        let result = match &set {
+            ConfigSet::Wrap(wrap) => config_handler.set("wrap", wrap),
+            ConfigSet::FontName(font_name) => config_handler.set("font_name", font_name),
+            ConfigSet::FontSize(font_size) => config_handler.set("font_size", font_size),
+            ConfigSet::TabWidth(tab_width) => config_handler.set("tab_width", tab_width),
+            ConfigSet::VimBindings(vim_bindings) => {
+                config_handler.set("vim_bindings", vim_bindings)
+            }
+            ConfigSet::AppTheme(app_theme) => config_handler.set("app_theme", app_theme),
+        };
+        if let Err(err) = result {
+            log::error!("failed to persist config field {:?}: {}", set, err);
+        }
+    }
+
+    /// Record `path` as the most recently opened project and persist the
+    /// list, so the next launch can reopen it.
+    pub fn push_recent_project(&mut self, config_handler: &CosmicConfig, path: PathBuf) {
+        self.recent_projects.retain(|recent| recent != &path);
+        self.recent_projects.insert(0, path);
+        self.recent_projects.truncate(MAX_RECENT_PROJECTS);
+
+        if let Err(err) = config_handler.set("recent_projects", &self.recent_projects) {
+            log::error!("failed to persist recent projects: {err}");
+        }
+    }
+}
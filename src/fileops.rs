@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::path::{Path, PathBuf};
+
+use crate::Message;
+use cosmic::{
+    iced::{widget::row, Length},
+    widget::{button, container, text, text_input},
+    Element,
+};
+
+/// State for the inline rename prompt shown while renaming a project entry.
+#[derive(Clone, Debug)]
+pub struct RenamePrompt {
+    pub path: PathBuf,
+    pub value: String,
+}
+
+impl RenamePrompt {
+    pub fn new(path: PathBuf) -> Self {
+        let value = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Self { path, value }
+    }
+
+    /// The renamed path: the prompt's target with its file name replaced.
+    pub fn target(&self) -> PathBuf {
+        match self.path.parent() {
+            Some(parent) => parent.join(&self.value),
+            None => PathBuf::from(&self.value),
+        }
+    }
+}
+
+pub fn rename_prompt_view<'a>(prompt: &'a RenamePrompt) -> Element<'a, Message> {
+    container(
+        row![
+            text(format!("Rename {}", prompt.path.to_string_lossy())),
+            text_input("New name...", &prompt.value)
+                .on_input(Message::ProjectRenameValue)
+                .on_submit(Message::ProjectRenameSubmit)
+                .width(Length::Fixed(240.0)),
+            button(text("Cancel")).on_press(Message::ProjectRenameCancel),
+        ]
+        .spacing(8)
+        .align_items(cosmic::iced::Alignment::Center),
+    )
+    .padding(8)
+    .style(cosmic::style::Container::Primary)
+    .into()
+}
+
+/// Row of file-management actions for the selected project entry.
+///
+/// Deliberately not a right-click context menu: `nav_bar` does not expose a
+/// per-item context menu hook in this tree, so these actions are rendered as
+/// an always-visible toolbar above the tab row whenever a nav entry is
+/// selected, instead of the per-entry context menu the request asked for.
+//TODO: move this to a right-click context menu over the nav bar entry once
+// the nav bar widget exposes that hook.
+pub fn project_actions_view<'a>(target: PathBuf, is_dir: bool) -> Element<'a, Message> {
+    let new_file_target = if is_dir {
+        target.clone()
+    } else {
+        target.parent().map(Path::to_path_buf).unwrap_or_default()
+    };
+    let new_folder_target = new_file_target.clone();
+    let rename_target = target.clone();
+    let trash_target = target;
+
+    row![
+        button(text("New File")).on_press(Message::ProjectNewFile(new_file_target)),
+        button(text("New Folder")).on_press(Message::ProjectNewFolder(new_folder_target)),
+        button(text("Rename")).on_press(Message::ProjectRenameStart(rename_target)),
+        button(text("Delete")).on_press(Message::ProjectTrash(trash_target)),
+    ]
+    .spacing(8)
+    .width(Length::Shrink)
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rename_prompt_defaults_value_to_file_name() {
+        let prompt = RenamePrompt::new(PathBuf::from("/project/src/main.rs"));
+        assert_eq!(prompt.value, "main.rs");
+    }
+
+    #[test]
+    fn target_replaces_file_name_within_parent() {
+        let mut prompt = RenamePrompt::new(PathBuf::from("/project/src/main.rs"));
+        prompt.value = "lib.rs".to_string();
+        assert_eq!(prompt.target(), PathBuf::from("/project/src/lib.rs"));
+    }
+
+    #[test]
+    fn target_with_no_parent_uses_value_alone() {
+        let mut prompt = RenamePrompt::new(PathBuf::from("main.rs"));
+        prompt.value = "lib.rs".to_string();
+        assert_eq!(prompt.target(), PathBuf::from("lib.rs"));
+    }
+}
@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::path::PathBuf;
+
+use crate::Message;
+use cosmic::{
+    iced::{widget::column, Length},
+    widget::{button, container, scrollable, text, text_input},
+    Element,
+};
+
+/// Score a `candidate` path against `query` as an ordered subsequence match.
+///
+/// Returns `None` if some character of `query` does not appear, in order, in
+/// `candidate`. Otherwise returns a score where higher is a better match:
+/// consecutive runs and matches that start at a path/word boundary (after
+/// `/`, `_`, `-`, or a lowercase-to-uppercase transition) are rewarded, and
+/// unmatched characters before the first match are lightly penalized.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query_chars: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    // Lower-case each char individually (rather than `candidate.to_lowercase()`
+    // as a whole) so `candidate_lower` stays the same length as
+    // `candidate_chars`: some characters (e.g. Turkish `İ`) lower-case to more
+    // than one `char` as a string, which would desync the two indices below.
+    let candidate_lower: Vec<char> = candidate_chars
+        .iter()
+        .map(|ch| ch.to_lowercase().next().unwrap_or(*ch))
+        .collect();
+
+    let mut score: i64 = 0;
+    let mut query_idx = 0;
+    let mut last_match_idx: Option<usize> = None;
+    let mut leading_unmatched: i64 = 0;
+
+    for (idx, &lower_ch) in candidate_lower.iter().enumerate() {
+        if query_idx >= query_chars.len() {
+            break;
+        }
+
+        if lower_ch == query_chars[query_idx] {
+            score += 1;
+
+            let is_boundary = match idx.checked_sub(1) {
+                Some(prev_idx) => {
+                    let prev = candidate_chars[prev_idx];
+                    prev == '/' || prev == '_' || prev == '-' || (prev.is_lowercase() && candidate_chars[idx].is_uppercase())
+                }
+                None => true,
+            };
+            if is_boundary {
+                score += 3;
+            }
+
+            if let Some(last_idx) = last_match_idx {
+                if idx == last_idx + 1 {
+                    score += 5;
+                }
+            } else {
+                leading_unmatched = idx as i64;
+            }
+
+            last_match_idx = Some(idx);
+            query_idx += 1;
+        }
+    }
+
+    if query_idx < query_chars.len() {
+        return None;
+    }
+
+    score -= leading_unmatched.min(10);
+
+    Some(score)
+}
+
+/// Maximum number of ranked results to keep and display.
+const MAX_RESULTS: usize = 50;
+
+/// State for the Ctrl-P style quick-open picker.
+#[derive(Clone, Debug, Default)]
+pub struct FindFile {
+    pub query: String,
+    pub results: Vec<PathBuf>,
+}
+
+impl FindFile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Recompute `results` by fuzzy-matching `query` against `paths`.
+    pub fn update_query(&mut self, query: String, paths: &[PathBuf]) {
+        self.query = query;
+
+        let mut scored: Vec<(i64, &PathBuf)> = paths
+            .iter()
+            .filter_map(|path| {
+                let candidate = path.to_string_lossy();
+                fuzzy_match(&self.query, &candidate).map(|score| (score, path))
+            })
+            .collect();
+
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+
+        self.results = scored
+            .into_iter()
+            .take(MAX_RESULTS)
+            .map(|(_, path)| path.clone())
+            .collect();
+    }
+}
+
+pub fn find_file_view<'a>(find: &'a FindFile) -> Element<'a, Message> {
+    let mut list = column::with_capacity(find.results.len()).spacing(4);
+    for path in find.results.iter() {
+        list = list.push(
+            button(text(path.to_string_lossy().to_string()))
+                .width(Length::Fill)
+                .on_press(Message::FindFileActivate(path.clone())),
+        );
+    }
+
+    let content = column::with_capacity(2)
+        .spacing(8)
+        .push(
+            text_input("Search files by name...", &find.query)
+                .on_input(Message::FindFileQuery)
+                .width(Length::Fill),
+        )
+        .push(scrollable(list).height(Length::Shrink));
+
+    container(content)
+        .padding(16)
+        .width(Length::Fixed(480.0))
+        .style(cosmic::style::Container::Primary)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_ordered_subsequence() {
+        assert!(fuzzy_match("mn", "main.rs").is_some());
+        assert!(fuzzy_match("nm", "main.rs").is_none());
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert_eq!(fuzzy_match("", "anything.rs"), Some(0));
+    }
+
+    #[test]
+    fn boundary_matches_score_higher_than_scattered() {
+        let boundary = fuzzy_match("mr", "main_rs").unwrap();
+        let scattered = fuzzy_match("mr", "mediocre").unwrap();
+        assert!(boundary > scattered);
+    }
+
+    #[test]
+    fn does_not_panic_on_chars_that_lowercase_to_multiple_chars() {
+        // 'İ' (U+0130, LATIN CAPITAL LETTER I WITH DOT ABOVE) lower-cases to
+        // the two-char string "i̇" via `str::to_lowercase`, which used to
+        // desync the index-for-index pairing between the candidate's chars
+        // and its lower-cased chars.
+        assert!(fuzzy_match("istanbul", "İstanbul.txt").is_some());
+    }
+}
@@ -11,25 +11,47 @@ use cosmic::{
     widget::{self, button, icon, nav_bar, segmented_button, view_switcher},
     ApplicationExt, Element,
 };
-use cosmic_text::{FontSystem, SyntaxSystem, ViMode};
+use cosmic_text::{Attrs, Cursor, FontSystem, Shaping, SyntaxSystem, ViMode};
 use std::{
     env, fs,
     path::{Path, PathBuf},
     sync::Mutex,
+    time::Duration,
 };
 
+use self::config::{Config, ConfigSet};
+mod config;
+
+use self::fileops::{project_actions_view, rename_prompt_view, RenamePrompt};
+mod fileops;
+
+use self::find::{find_file_view, FindFile};
+mod find;
+
 use self::menu::menu_bar;
 mod menu;
 
+use self::outline::{compute_outline, outline_view, syntax_name_for_path, OutlineItem};
+mod outline;
+
 use self::project::ProjectNode;
 mod project;
 
+use self::remote::{remote_prompt_view, FileSystem, LocalFileSystem, RemotePrompt, SshFileSystem, SshTarget};
+mod remote;
+
+use self::search::{search_command, search_view, SearchHit, SearchState};
+mod search;
+
 use self::tab::Tab;
 mod tab;
 
 use self::text_box::text_box;
 mod text_box;
 
+use self::vi::{apply_substitute, find_match, parse_ex_command, ExCommand};
+mod vi;
+
 //TODO: re-use iced FONT_SYSTEM
 lazy_static::lazy_static! {
     static ref FONT_SYSTEM: Mutex<FontSystem> = Mutex::new(FontSystem::new());
@@ -46,23 +68,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-#[derive(Clone, Debug)]
-pub struct Config {
-    wrap: bool,
-}
-
-impl Config {
-    //TODO: load from cosmic-config
-    pub fn new() -> Self {
-        Self { wrap: false }
-    }
-}
-
 pub struct App {
     core: Core,
     nav_model: segmented_button::SingleSelectModel,
     tab_model: segmented_button::SingleSelectModel,
+    config_handler: Option<cosmic_config::Config>,
     config: Config,
+    find_opt: Option<FindFile>,
+    search_opt: Option<SearchState>,
+    outline: Vec<OutlineItem>,
+    last_search: Option<(String, bool)>,
+    rename_opt: Option<RenamePrompt>,
+    remote_opt: Option<RemotePrompt>,
 }
 
 #[allow(dead_code)]
@@ -76,6 +93,47 @@ pub enum Message {
     TabClose(segmented_button::Entity),
     Todo,
     Wrap(bool),
+    FindFileOpen,
+    FindFileQuery(String),
+    FindFileActivate(PathBuf),
+    SearchOpen,
+    SearchQuery {
+        query: String,
+        regex: bool,
+        case_sensitive: bool,
+    },
+    SearchBatch {
+        hits: Vec<SearchHit>,
+        remaining: Vec<PathBuf>,
+        query: String,
+        regex: bool,
+        case_sensitive: bool,
+    },
+    SearchGoto {
+        path: PathBuf,
+        line: usize,
+        col: usize,
+    },
+    OutlineGoto(usize),
+    OutlineTick,
+    ConfigSet(ConfigSet),
+    ConfigReload(Config),
+    ViCommand(String),
+    ViSearchSubmit { pattern: String, forwards: bool },
+    ViSearchRepeat(bool),
+    ProjectNewFile(PathBuf),
+    ProjectNewFolder(PathBuf),
+    ProjectRenameStart(PathBuf),
+    ProjectRenameValue(String),
+    ProjectRenameCancel,
+    ProjectRenameSubmit,
+    ProjectRename { from: PathBuf, to: PathBuf },
+    ProjectTrash(PathBuf),
+    OpenRemoteDialog,
+    OpenRemoteValue(String),
+    OpenRemoteCancel,
+    OpenRemoteSubmit,
+    OpenRemote(String),
 }
 
 impl App {
@@ -87,8 +145,13 @@ impl App {
         self.tab_model.active_data_mut()
     }
 
+    /// List `path`'s entries through `LocalFileSystem` (rather than calling
+    /// `std::fs::read_dir` directly) so this is the one nav-tree-building
+    /// caller already behind the `FileSystem` trait; `Tab::open`/`Tab::save`
+    /// (in tab.rs) still call `std::fs` directly and remain on the TODO in
+    /// remote.rs.
     fn open_folder<P: AsRef<Path>>(&mut self, path: P, mut position: u16, indent: u16) {
-        let read_dir = match fs::read_dir(&path) {
+        let entries = match LocalFileSystem.read_dir(&path.as_ref().to_string_lossy()) {
             Ok(ok) => ok,
             Err(err) => {
                 log::error!("failed to read directory {:?}: {}", path.as_ref(), err);
@@ -97,20 +160,8 @@ impl App {
         };
 
         let mut nodes = Vec::new();
-        for entry_res in read_dir {
-            let entry = match entry_res {
-                Ok(ok) => ok,
-                Err(err) => {
-                    log::error!(
-                        "failed to read entry in directory {:?}: {}",
-                        path.as_ref(),
-                        err
-                    );
-                    continue;
-                }
-            };
-
-            let entry_path = entry.path();
+        for entry in entries {
+            let entry_path = PathBuf::from(&entry.path);
             let node = match ProjectNode::new(&entry_path) {
                 Ok(ok) => ok,
                 Err(err) => {
@@ -141,7 +192,62 @@ impl App {
         }
     }
 
-    pub fn open_project<P: AsRef<Path>>(&mut self, path: P) {
+    /// Pick a filesystem name under `parent` starting from `stem` that does
+    /// not already exist, by appending an incrementing counter.
+    fn unique_path(parent: &Path, stem: &str) -> PathBuf {
+        let mut candidate = parent.join(stem);
+        let mut counter = 1;
+        while candidate.exists() {
+            counter += 1;
+            candidate = parent.join(format!("{stem} {counter}"));
+        }
+        candidate
+    }
+
+    /// Reload a folder's children in the nav tree, the same way
+    /// `on_nav_select` does when a folder is toggled closed and reopened.
+    fn refresh_project_folder(&mut self, folder_path: &Path) {
+        let mut target_id = None;
+        for id in self.nav_model.iter() {
+            if let Some(ProjectNode::Folder { path, .. }) = self.nav_model.data::<ProjectNode>(id)
+            {
+                if path == folder_path {
+                    target_id = Some(id);
+                    break;
+                }
+            }
+        }
+
+        let Some(id) = target_id else {
+            return;
+        };
+        let position = self.nav_model.position(id).unwrap_or(0);
+        let indent = self.nav_model.indent(id).unwrap_or(0);
+
+        loop {
+            let child_id = match self.nav_model.entity_at(position + 1) {
+                Some(some) => some,
+                None => break,
+            };
+
+            if self.nav_model.indent(child_id).unwrap_or(0) > indent {
+                self.nav_model.remove(child_id);
+            } else {
+                break;
+            }
+        }
+
+        self.open_folder(folder_path, position + 1, indent + 1);
+    }
+
+    /// Open `path` as a project, adding it to the nav tree.
+    ///
+    /// `remember` controls whether `path` is pushed onto the recent-projects
+    /// list: it should be `true` when the user opens a project during this
+    /// session, but `false` when replaying `recent_projects` itself at
+    /// startup, since re-pushing each entry in its stored order would both
+    /// reverse the list and rewrite the config file on every launch.
+    pub fn open_project<P: AsRef<Path>>(&mut self, path: P, remember: bool) {
         let node = match ProjectNode::new(&path) {
             Ok(mut node) => {
                 match &mut node {
@@ -176,6 +282,49 @@ impl App {
         let position = self.nav_model.position(id).unwrap_or(0);
 
         self.open_folder(&path, position + 1, 1);
+
+        if remember {
+            if let Some(config_handler) = &self.config_handler {
+                self.config
+                    .push_recent_project(config_handler, path.as_ref().to_path_buf());
+            }
+        }
+    }
+
+    /// Parse an `ssh://user@host/path` URI and report the outcome.
+    ///
+    /// This is scaffolding only (see the doc comment on `remote::FileSystem`):
+    /// `SshFileSystem::connect` does not open a network connection, so the
+    /// status string returned here (and shown in the "Open Remote..." dialog
+    /// via `RemotePrompt::status`) must not claim connectivity was verified.
+    //TODO: once ProjectNode/Tab are refactored behind `remote::FileSystem`,
+    // build the nav tree from `SshFileSystem::read_dir` instead of just
+    // parsing the URI
+    pub fn open_remote(&mut self, uri: String) -> String {
+        let Some(target) = SshTarget::parse(&uri) else {
+            let message = format!("invalid remote URI {:?}, expected ssh://user@host/path", uri);
+            log::error!("{message}");
+            return message;
+        };
+
+        match SshFileSystem::connect(target) {
+            Ok(remote_fs) => {
+                // `SshFileSystem::connect` only parses and stores the target;
+                // it does not open a socket, so this must not claim a
+                // connection was verified.
+                let message = format!(
+                    "{} is a valid remote URI, but SSH/SFTP connections and remote project trees are not supported yet",
+                    remote_fs.target.host
+                );
+                log::info!("{message}");
+                message
+            }
+            Err(err) => {
+                let message = format!("failed to connect to remote {:?}: {}", uri, err);
+                log::error!("{message}");
+                message
+            }
+        }
     }
 
     pub fn open_tab(&mut self, path_opt: Option<PathBuf>) {
@@ -193,6 +342,89 @@ impl App {
             .activate();
     }
 
+    /// Collect every file path known to the project nav tree, for use by the
+    /// fuzzy file finder.
+    pub fn project_paths(&self) -> Vec<PathBuf> {
+        let mut paths = Vec::new();
+        for id in self.nav_model.iter() {
+            if let Some(ProjectNode::File { path, .. }) = self.nav_model.data::<ProjectNode>(id) {
+                paths.push(path.clone());
+            }
+        }
+        paths
+    }
+
+    /// Collect the root folder of every opened project, for use by
+    /// project-wide text search.
+    pub fn project_roots(&self) -> Vec<PathBuf> {
+        let mut roots = Vec::new();
+        for id in self.nav_model.iter() {
+            if let Some(ProjectNode::Folder { path, root: true, .. }) =
+                self.nav_model.data::<ProjectNode>(id)
+            {
+                roots.push(path.clone());
+            }
+        }
+        roots
+    }
+
+    /// Join the active tab's buffer into a single string, if a tab is open.
+    fn buffer_text(&self) -> Option<String> {
+        let tab = self.active_tab()?;
+        let editor = tab.editor.lock().unwrap();
+        Some(
+            editor
+                .buffer()
+                .lines
+                .iter()
+                .map(|line| line.text())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    /// Move the active tab's cursor to the next match of `pattern`.
+    fn run_search(&mut self, pattern: &str, forwards: bool) {
+        let (from_line, from_col) = match self.active_tab() {
+            Some(tab) => {
+                let cursor = tab.editor.lock().unwrap().cursor();
+                (cursor.line, cursor.index)
+            }
+            None => return,
+        };
+        let Some(text) = self.buffer_text() else {
+            return;
+        };
+
+        if let Some((line, col)) = find_match(&text, pattern, from_line, from_col, forwards) {
+            if let Some(tab) = self.active_tab_mut() {
+                tab.editor.lock().unwrap().set_cursor(Cursor::new(line, col));
+            }
+        }
+    }
+
+    /// Recompute the symbol outline for the active tab.
+    pub fn refresh_outline(&mut self) {
+        self.outline = match self.active_tab() {
+            Some(tab) => {
+                let syntax_name = match &tab.path_opt {
+                    Some(path) => syntax_name_for_path(path),
+                    None => String::new(),
+                };
+                let editor = tab.editor.lock().unwrap();
+                let buffer_text = editor
+                    .buffer()
+                    .lines
+                    .iter()
+                    .map(|line| line.text())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                compute_outline(&buffer_text, &syntax_name)
+            }
+            None => Vec::new(),
+        };
+    }
+
     pub fn update_title(&mut self) -> Command<Message> {
         let title = match self.active_tab() {
             Some(tab) => tab.title(),
@@ -228,19 +460,52 @@ impl cosmic::Application for App {
 
     /// Creates the application, and optionally emits command on initialize.
     fn init(core: Core, _flags: Self::Flags) -> (Self, Command<Self::Message>) {
+        let config_handler = Config::config_handler();
+        let config = match &config_handler {
+            Some(config_handler) => Config::load(config_handler),
+            None => Config::default(),
+        };
+        let recent_projects = config.recent_projects.clone();
+
         let mut app = App {
             core,
             nav_model: nav_bar::Model::builder().build(),
             tab_model: segmented_button::Model::builder().build(),
-            config: Config::new(),
+            config_handler,
+            config,
+            find_opt: None,
+            search_opt: None,
+            outline: Vec::new(),
+            last_search: None,
+            rename_opt: None,
+            remote_opt: None,
         };
 
-        for arg in env::args().skip(1) {
-            let path = PathBuf::from(arg);
-            if path.is_dir() {
-                app.open_project(path);
-            } else {
-                app.open_tab(Some(path));
+        let args: Vec<String> = env::args().skip(1).collect();
+        if args.is_empty() {
+            // Reopen the previous session's projects when launched with no
+            // arguments. This replays `recent_projects` verbatim, so it must
+            // not push back onto that same list (that would both reverse it
+            // and rewrite the config file on every launch).
+            for path in recent_projects {
+                if path.is_dir() {
+                    app.open_project(path, false);
+                }
+            }
+        } else {
+            for arg in args {
+                if arg.starts_with("ssh://") {
+                    // No dialog is open to show a status in; the message is
+                    // still logged inside `open_remote`.
+                    let _status = app.open_remote(arg);
+                    continue;
+                }
+                let path = PathBuf::from(arg);
+                if path.is_dir() {
+                    app.open_project(path, true);
+                } else {
+                    app.open_tab(Some(path));
+                }
             }
         }
 
@@ -254,6 +519,7 @@ impl cosmic::Application for App {
             app.open_tab(None);
         }
 
+        app.refresh_outline();
         let command = app.update_title();
         (app, command)
     }
@@ -307,6 +573,7 @@ impl cosmic::Application for App {
             ProjectNode::File { path, .. } => {
                 //TODO: go to already open file if possible
                 self.open_tab(Some(path.clone()));
+                self.refresh_outline();
                 self.update_title()
             }
         }
@@ -316,6 +583,7 @@ impl cosmic::Application for App {
         match message {
             Message::New => {
                 self.open_tab(None);
+                self.refresh_outline();
                 return self.update_title();
             }
             Message::OpenDialog => {
@@ -332,6 +600,7 @@ impl cosmic::Application for App {
             }
             Message::Open(path) => {
                 self.open_tab(Some(path));
+                self.refresh_outline();
                 return self.update_title();
             }
             Message::Save => {
@@ -357,6 +626,7 @@ impl cosmic::Application for App {
             }
             Message::TabActivate(entity) => {
                 self.tab_model.activate(entity);
+                self.refresh_outline();
                 return self.update_title();
             }
             Message::TabClose(entity) => {
@@ -377,13 +647,116 @@ impl cosmic::Application for App {
                     self.open_tab(None);
                 }
 
+                self.refresh_outline();
                 return self.update_title();
             }
             Message::Todo => {
                 log::warn!("TODO");
             }
+            Message::FindFileOpen => {
+                //TODO: add a menu entry once menu.rs exposes custom actions;
+                // bound to Ctrl+P via the keyboard subscription in `subscription()`
+                self.find_opt = Some(FindFile::new());
+            }
+            Message::FindFileQuery(query) => {
+                let paths = self.project_paths();
+                if let Some(find) = &mut self.find_opt {
+                    find.update_query(query, &paths);
+                }
+            }
+            Message::FindFileActivate(path) => {
+                self.find_opt = None;
+                self.open_tab(Some(path));
+                self.refresh_outline();
+                return self.update_title();
+            }
+            Message::SearchOpen => {
+                //TODO: add a menu entry once menu.rs exposes custom actions;
+                // bound to Ctrl+Shift+F via the keyboard subscription in `subscription()`
+                self.search_opt = Some(SearchState::new());
+            }
+            Message::SearchQuery {
+                query,
+                regex,
+                case_sensitive,
+            } => {
+                let roots = self.project_roots();
+                if let Some(search) = &mut self.search_opt {
+                    search.query = query.clone();
+                    search.regex = regex;
+                    search.case_sensitive = case_sensitive;
+                    search.results.clear();
+                    search.searching = !query.is_empty();
+                }
+                if query.is_empty() {
+                    return Command::none();
+                }
+                return search_command(roots, query, regex, case_sensitive);
+            }
+            Message::SearchBatch {
+                hits,
+                remaining,
+                query,
+                regex,
+                case_sensitive,
+            } => {
+                let stale = match &self.search_opt {
+                    Some(search) => {
+                        search.query != query
+                            || search.regex != regex
+                            || search.case_sensitive != case_sensitive
+                    }
+                    None => true,
+                };
+                if stale {
+                    return Command::none();
+                }
+
+                if let Some(search) = &mut self.search_opt {
+                    search.results.extend(hits);
+                    search.searching = !remaining.is_empty();
+                }
+
+                if !remaining.is_empty() {
+                    return search_command(remaining, query, regex, case_sensitive);
+                }
+            }
+            Message::SearchGoto { path, line, col } => {
+                self.search_opt = None;
+                //TODO: if `path` is already open in another tab, activate that
+                // tab instead of opening a second one (see the same TODO on
+                // `ProjectNode::File` in `on_nav_select`)
+                self.open_tab(Some(path));
+                if let Some(tab) = self.active_tab_mut() {
+                    tab.editor.lock().unwrap().set_cursor(Cursor::new(line, col));
+                }
+                self.refresh_outline();
+                return self.update_title();
+            }
+            Message::OutlineGoto(line) => {
+                if let Some(tab) = self.active_tab_mut() {
+                    let mut editor = tab.editor.lock().unwrap();
+                    let cursor = editor.cursor();
+                    editor.set_cursor(Cursor::new(line, cursor.index));
+                }
+            }
+            //TODO: text_box.rs mutates `tab.editor` directly on every
+            // keystroke without emitting a Message, so there is no edit
+            // event to hook `refresh_outline()` off of; this periodic tick
+            // (driven by the `OutlineTick` subscription below) is a stand-in
+            // until text_box.rs can report "buffer changed".
+            Message::OutlineTick => {
+                self.refresh_outline();
+            }
             Message::Wrap(wrap) => {
-                self.config.wrap = wrap;
+                return self.update(Message::ConfigSet(ConfigSet::Wrap(wrap)));
+            }
+            Message::ConfigSet(set) => {
+                match &self.config_handler {
+                    Some(config_handler) => self.config.set(config_handler, set),
+                    None => self.config.apply(set),
+                }
+
                 //TODO: provide iterator over data
                 let entities: Vec<_> = self.tab_model.iter().collect();
                 for entity in entities {
@@ -392,17 +765,340 @@ impl cosmic::Application for App {
                     }
                 }
             }
+            Message::ConfigReload(config) => {
+                self.config = config;
+
+                let entities: Vec<_> = self.tab_model.iter().collect();
+                for entity in entities {
+                    if let Some(tab) = self.tab_model.data_mut::<Tab>(entity) {
+                        tab.set_config(&self.config);
+                    }
+                }
+            }
+            // Emitted by the `vi_subscription` keyboard listener in
+            // `subscription()` when Enter is pressed while the active tab is
+            // in `ViMode::Command`.
+            Message::ViCommand(input) => {
+                let (current_line, last_line) = match self.active_tab() {
+                    Some(tab) => {
+                        let editor = tab.editor.lock().unwrap();
+                        let last_line = editor.buffer().lines.len().saturating_sub(1);
+                        (editor.cursor().line, last_line)
+                    }
+                    None => (0, 0),
+                };
+
+                match parse_ex_command(&input, current_line, last_line) {
+                    ExCommand::Write => return self.update(Message::Save),
+                    ExCommand::Quit { force: _ } => {
+                        let entity = self.tab_model.active();
+                        return self.update(Message::TabClose(entity));
+                    }
+                    ExCommand::WriteQuit => {
+                        let save_command = self.update(Message::Save);
+                        let entity = self.tab_model.active();
+                        let close_command = self.update(Message::TabClose(entity));
+                        return Command::batch([save_command, close_command]);
+                    }
+                    ExCommand::Edit(path) => return self.update(Message::Open(path)),
+                    ExCommand::GotoLine(line) => {
+                        if let Some(tab) = self.active_tab_mut() {
+                            tab.editor.lock().unwrap().set_cursor(Cursor::new(line, 0));
+                        }
+                    }
+                    ExCommand::GotoEnd => {
+                        if let Some(tab) = self.active_tab_mut() {
+                            let mut editor = tab.editor.lock().unwrap();
+                            let last_line = editor.buffer().lines.len().saturating_sub(1);
+                            editor.set_cursor(Cursor::new(last_line, 0));
+                        }
+                    }
+                    ExCommand::Substitute {
+                        start_line,
+                        end_line,
+                        pattern,
+                        replacement,
+                        global,
+                    } => {
+                        let text = self.buffer_text();
+                        if let (Some(text), Some(tab)) = (text, self.active_tab_mut()) {
+                            if let Some(new_text) = apply_substitute(
+                                &text,
+                                start_line,
+                                end_line,
+                                &pattern,
+                                &replacement,
+                                global,
+                            ) {
+                                tab.editor.lock().unwrap().buffer_mut().set_text(
+                                    &mut FONT_SYSTEM.lock().unwrap(),
+                                    &new_text,
+                                    Attrs::new(),
+                                    Shaping::Advanced,
+                                );
+                            }
+                        }
+                    }
+                    ExCommand::Unknown(cmd) => {
+                        log::warn!("unknown Ex command: {cmd}");
+                    }
+                }
+            }
+            // Emitted by `vi_subscription` when Enter is pressed while the
+            // active tab is in `ViMode::Search`.
+            Message::ViSearchSubmit { pattern, forwards } => {
+                self.last_search = Some((pattern.clone(), forwards));
+                self.run_search(&pattern, forwards);
+            }
+            // Emitted by `vi_subscription` for the `n`/`N` normal-mode motions.
+            Message::ViSearchRepeat(same_direction) => {
+                if let Some((pattern, forwards)) = self.last_search.clone() {
+                    let direction = if same_direction { forwards } else { !forwards };
+                    self.run_search(&pattern, direction);
+                }
+            }
+            Message::ProjectNewFile(parent) => {
+                let path = Self::unique_path(&parent, "Untitled");
+                match fs::File::create(&path) {
+                    Ok(_) => {
+                        self.refresh_project_folder(&parent);
+                        self.open_tab(Some(path));
+                        self.refresh_outline();
+                        return self.update_title();
+                    }
+                    Err(err) => log::error!("failed to create file {:?}: {}", path, err),
+                }
+            }
+            Message::ProjectNewFolder(parent) => {
+                let path = Self::unique_path(&parent, "New Folder");
+                if let Err(err) = fs::create_dir(&path) {
+                    log::error!("failed to create folder {:?}: {}", path, err);
+                } else {
+                    self.refresh_project_folder(&parent);
+                }
+            }
+            Message::ProjectRenameStart(path) => {
+                self.rename_opt = Some(RenamePrompt::new(path));
+            }
+            Message::ProjectRenameValue(value) => {
+                if let Some(prompt) = &mut self.rename_opt {
+                    prompt.value = value;
+                }
+            }
+            Message::ProjectRenameCancel => {
+                self.rename_opt = None;
+            }
+            Message::ProjectRenameSubmit => {
+                if let Some(prompt) = self.rename_opt.take() {
+                    let to = prompt.target();
+                    return self.update(Message::ProjectRename {
+                        from: prompt.path,
+                        to,
+                    });
+                }
+            }
+            Message::ProjectRename { from, to } => {
+                if let Err(err) = fs::rename(&from, &to) {
+                    log::error!("failed to rename {:?} to {:?}: {}", from, to, err);
+                } else {
+                    if let Some(parent) = from.parent() {
+                        self.refresh_project_folder(parent);
+                    }
+
+                    // `from` may be a folder, in which case every tab open
+                    // to a file *inside* it also needs its path rewritten,
+                    // not just a tab open to `from` itself.
+                    let entities: Vec<_> = self.tab_model.iter().collect();
+                    for entity in entities {
+                        let renamed_title = self.tab_model.data_mut::<Tab>(entity).and_then(|tab| {
+                            let tab_path = tab.path_opt.as_ref()?;
+                            let rest = tab_path.strip_prefix(&from).ok()?;
+                            // `rest` is empty when `tab_path == from` (the
+                            // single-file rename case): `to.join("")` would
+                            // append a trailing-slash component, turning e.g.
+                            // `/a/b/lib.rs` into `/a/b/lib.rs/`, which then
+                            // fails to save with "Is a directory".
+                            tab.path_opt = Some(if rest.as_os_str().is_empty() {
+                                to.clone()
+                            } else {
+                                to.join(rest)
+                            });
+                            Some(tab.title())
+                        });
+                        if let Some(title) = renamed_title {
+                            self.tab_model.text_set(entity, title);
+                        }
+                    }
+                }
+            }
+            Message::ProjectTrash(path) => match trash::delete(&path) {
+                Ok(()) => {
+                    if let Some(parent) = path.parent() {
+                        self.refresh_project_folder(parent);
+                    }
+
+                    // `path` may be a folder, so close every tab open to a
+                    // file inside it, not just a tab open to `path` itself.
+                    let entities: Vec<_> = self.tab_model.iter().collect();
+                    let mut commands = Vec::new();
+                    for entity in entities {
+                        let matches = self
+                            .tab_model
+                            .data::<Tab>(entity)
+                            .and_then(|tab| tab.path_opt.as_deref())
+                            .map(|tab_path| tab_path.starts_with(&path))
+                            .unwrap_or(false);
+                        if matches {
+                            commands.push(self.update(Message::TabClose(entity)));
+                        }
+                    }
+                    return Command::batch(commands);
+                }
+                Err(err) => log::error!("failed to trash {:?}: {}", path, err),
+            },
+            Message::OpenRemoteDialog => {
+                self.remote_opt = Some(RemotePrompt::default());
+            }
+            Message::OpenRemoteValue(value) => {
+                if let Some(remote) = &mut self.remote_opt {
+                    remote.value = value;
+                }
+            }
+            Message::OpenRemoteCancel => {
+                self.remote_opt = None;
+            }
+            Message::OpenRemoteSubmit => {
+                if let Some(remote) = &self.remote_opt {
+                    return self.update(Message::OpenRemote(remote.value.clone()));
+                }
+            }
+            Message::OpenRemote(uri) => {
+                let status = self.open_remote(uri);
+                if let Some(remote) = &mut self.remote_opt {
+                    remote.status = Some(status);
+                }
+            }
         }
 
         Command::none()
     }
 
+    fn subscription(&self) -> cosmic::iced::Subscription<Message> {
+        struct ConfigSubscription;
+
+        let config_subscription = cosmic_config::config_subscription(
+            std::any::TypeId::of::<ConfigSubscription>(),
+            Self::APP_ID.into(),
+            config::CONFIG_VERSION,
+        )
+        .map(|update| {
+            for err in update.errors {
+                log::error!("failed to watch config: {err}");
+            }
+            Message::ConfigReload(Config::load(&update.config))
+        });
+
+        // Recompute the outline periodically rather than only on tab
+        // lifecycle events, so it stays roughly in sync while the buffer is
+        // being edited.
+        let outline_subscription =
+            cosmic::iced::time::every(Duration::from_millis(750)).map(|_| Message::OutlineTick);
+
+        // Global shortcuts that don't depend on menu.rs exposing custom
+        // actions: Ctrl+P opens the fuzzy file finder, Ctrl+Shift+F opens
+        // the project-wide search panel.
+        let shortcut_subscription =
+            cosmic::iced::keyboard::on_key_press(|key, modifiers| match key.as_ref() {
+                cosmic::iced::keyboard::Key::Character(c)
+                    if c.eq_ignore_ascii_case("p") && modifiers.control() && !modifiers.shift() =>
+                {
+                    Some(Message::FindFileOpen)
+                }
+                cosmic::iced::keyboard::Key::Character(c)
+                    if c.eq_ignore_ascii_case("f") && modifiers.control() && modifiers.shift() =>
+                {
+                    Some(Message::SearchOpen)
+                }
+                _ => None,
+            });
+
+        // text_box.rs owns raw key routing into
+        // `cosmic_text`'s `Editor`, which is what actually accumulates the
+        // `ViMode::Command`/`ViMode::Search` value as the user types. This
+        // listens only for the keys that *submit* or *repeat* what the
+        // editor has already accumulated, gated on the active tab's current
+        // mode so it does not interfere with ordinary typing in Insert mode.
+        let (enter_message, in_normal_mode) = match self.active_tab() {
+            Some(tab) => {
+                let editor = tab.editor.lock().unwrap();
+                match editor.mode() {
+                    ViMode::Command { value } => (Some(Message::ViCommand(value.clone())), false),
+                    ViMode::Search { value, forwards } => (
+                        Some(Message::ViSearchSubmit {
+                            pattern: value.clone(),
+                            forwards: *forwards,
+                        }),
+                        false,
+                    ),
+                    ViMode::Normal => (None, true),
+                    _ => (None, false),
+                }
+            }
+            None => (None, false),
+        };
+        let vi_subscription = cosmic::iced::keyboard::on_key_press(move |key, _modifiers| {
+            match key.as_ref() {
+                cosmic::iced::keyboard::Key::Named(cosmic::iced::keyboard::key::Named::Enter) => {
+                    enter_message.clone()
+                }
+                cosmic::iced::keyboard::Key::Character(c) if in_normal_mode && c.as_str() == "n" => {
+                    Some(Message::ViSearchRepeat(true))
+                }
+                cosmic::iced::keyboard::Key::Character(c) if in_normal_mode && c.as_str() == "N" => {
+                    Some(Message::ViSearchRepeat(false))
+                }
+                _ => None,
+            }
+        });
+
+        cosmic::iced::Subscription::batch([
+            config_subscription,
+            outline_subscription,
+            shortcut_subscription,
+            vi_subscription,
+        ])
+    }
+
     fn header_start(&self) -> Vec<Element<Message>> {
         vec![menu_bar(&self.config)]
     }
 
     fn view(&self) -> Element<Message> {
-        let mut tab_column = widget::column::with_capacity(3).padding([0, 16]);
+        let mut tab_column = widget::column::with_capacity(8).padding([0, 16]);
+
+        if let Some(find) = &self.find_opt {
+            tab_column = tab_column.push(find_file_view(find));
+        }
+
+        if let Some(search) = &self.search_opt {
+            tab_column = tab_column.push(search_view(search));
+        }
+
+        if let Some(node) = self.nav_model.active_data::<ProjectNode>() {
+            let (target, is_dir) = match node {
+                ProjectNode::Folder { path, .. } => (path.clone(), true),
+                ProjectNode::File { path, .. } => (path.clone(), false),
+            };
+            tab_column = tab_column.push(project_actions_view(target, is_dir));
+        }
+
+        if let Some(rename_prompt) = &self.rename_opt {
+            tab_column = tab_column.push(rename_prompt_view(rename_prompt));
+        }
+
+        if let Some(remote_prompt) = &self.remote_opt {
+            tab_column = tab_column.push(remote_prompt_view(remote_prompt));
+        }
 
         tab_column = tab_column.push(
             row![
@@ -413,6 +1109,10 @@ impl cosmic::Application for App {
                 button(icon::from_name("list-add-symbolic").size(16).icon())
                     .on_press(Message::New)
                     .padding(8)
+                    .style(style::Button::Icon),
+                button(icon::from_name("network-server-symbolic").size(16).icon())
+                    .on_press(Message::OpenRemoteDialog)
+                    .padding(8)
                     .style(style::Button::Icon)
             ]
             .align_items(Alignment::Center),
@@ -420,15 +1120,28 @@ impl cosmic::Application for App {
 
         match self.active_tab() {
             Some(tab) => {
-                tab_column = tab_column.push(text_box(&tab.editor).padding(8));
+                let mut editor_row = row![text_box(&tab.editor).padding(8)].width(Length::Fill);
+                if !self.outline.is_empty() {
+                    editor_row = editor_row.push(
+                        widget::container(outline_view(&self.outline))
+                            .width(Length::Fixed(240.0))
+                            .padding(8),
+                    );
+                }
+                tab_column = tab_column.push(editor_row);
+                let file_name = tab
+                    .path_opt
+                    .as_ref()
+                    .map(|path| path.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "[No Name]".to_string());
+                let cursor = tab.editor.lock().unwrap().cursor();
+                let position = format!("{}:{}", cursor.line + 1, cursor.index + 1);
                 let status = match tab.editor.lock().unwrap().mode() {
                     ViMode::Passthrough => {
-                        //TODO: status line
-                        String::new()
+                        format!("{file_name} -- {position}")
                     }
                     ViMode::Normal => {
-                        //TODO: status line
-                        String::new()
+                        format!("-- NORMAL --  {file_name}  {position}")
                     }
                     ViMode::Insert => {
                         format!("-- INSERT --")
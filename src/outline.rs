@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::path::Path;
+
+use crate::Message;
+use cosmic::{
+    iced::{
+        widget::{column, row, Space},
+        Length,
+    },
+    widget::{button, scrollable, text},
+    Element,
+};
+
+/// A single entry in a tab's symbol outline: a function, struct, class, or
+/// heading, along with the line it starts on.
+#[derive(Clone, Debug)]
+pub struct OutlineItem {
+    pub name: String,
+    pub line: usize,
+    pub depth: u16,
+}
+
+/// Regex-per-language heuristics used when the detected syntax has no
+/// richer symbol information to draw on. Each entry is `(pattern, depth)`;
+/// the first capture group becomes the outline item's name, and a line whose
+/// match has no first capture group adds no item.
+///
+/// Markdown is not listed here: `compute_outline` handles it before this
+/// function is ever consulted, since a heading's depth comes from its `#`
+/// count rather than a fixed value.
+fn heuristics_for(syntax_name: &str) -> &'static [(&'static str, u16)] {
+    match syntax_name.to_lowercase().as_str() {
+        "rust" => &[
+            (r"^\s*(?:pub(?:\([^)]*\))?\s+)?fn\s+([A-Za-z0-9_]+)", 0),
+            (r"^\s*(?:pub(?:\([^)]*\))?\s+)?struct\s+([A-Za-z0-9_]+)", 0),
+            (r"^\s*(?:pub(?:\([^)]*\))?\s+)?enum\s+([A-Za-z0-9_]+)", 0),
+            (r"^\s*(?:pub(?:\([^)]*\))?\s+)?trait\s+([A-Za-z0-9_]+)", 0),
+        ],
+        "python" => &[
+            (r"^\s*def\s+([A-Za-z0-9_]+)", 1),
+            (r"^\s*class\s+([A-Za-z0-9_]+)", 0),
+        ],
+        "javascript" | "typescript" => &[
+            (r"^\s*function\s+([A-Za-z0-9_]+)", 0),
+            (r"^\s*class\s+([A-Za-z0-9_]+)", 0),
+        ],
+        _ => &[],
+    }
+}
+
+/// Build a flat outline from `text`, keyed off the tab's detected syntax
+/// name (e.g. "Rust", "Python", "Markdown" as reported by `SYNTAX_SYSTEM`).
+///
+/// This always uses the regex-per-language fallback described in the
+/// heuristics table; richer symbol extraction can replace individual
+/// entries without changing the outline's shape.
+pub fn compute_outline(text: &str, syntax_name: &str) -> Vec<OutlineItem> {
+    let mut items = Vec::new();
+
+    if syntax_name.eq_ignore_ascii_case("markdown") {
+        for (line_idx, line) in text.lines().enumerate() {
+            if let Some(rest) = line.strip_prefix('#') {
+                let depth = (line.len() - rest.trim_start_matches('#').len() - 1) as u16;
+                let name = rest.trim_start_matches('#').trim().to_string();
+                if !name.is_empty() {
+                    items.push(OutlineItem {
+                        name,
+                        line: line_idx,
+                        depth,
+                    });
+                }
+            }
+        }
+        return items;
+    }
+
+    let patterns: Vec<(regex::Regex, u16)> = heuristics_for(syntax_name)
+        .iter()
+        .filter_map(|(pattern, depth)| regex::Regex::new(pattern).ok().map(|re| (re, *depth)))
+        .collect();
+
+    for (line_idx, line) in text.lines().enumerate() {
+        for (re, depth) in &patterns {
+            if let Some(captures) = re.captures(line) {
+                if let Some(name) = captures.get(1) {
+                    items.push(OutlineItem {
+                        name: name.as_str().to_string(),
+                        line: line_idx,
+                        depth: *depth,
+                    });
+                    break;
+                }
+            }
+        }
+    }
+
+    items
+}
+
+/// Map a file's extension to the syntax name used to pick an outline
+/// heuristic, by asking `SYNTAX_SYSTEM` (the same syntax database used for
+/// highlighting) rather than hand-rolling a second extension table. Falls
+/// back to an empty name for extensions with no registered syntax, which
+/// simply yields an empty outline.
+pub fn syntax_name_for_path(path: &Path) -> String {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default();
+    crate::SYNTAX_SYSTEM
+        .syntax_set
+        .find_syntax_by_extension(extension)
+        .map(|syntax| syntax.name.clone())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_rust_items() {
+        let text = "fn main() {}\nstruct Foo;\npub fn bar() {}\n";
+        let items = compute_outline(text, "rust");
+        let names: Vec<&str> = items.iter().map(|item| item.name.as_str()).collect();
+        assert_eq!(names, vec!["main", "Foo", "bar"]);
+    }
+
+    #[test]
+    fn markdown_headings_use_hash_count_as_depth() {
+        let text = "# Title\n## Section\ntext\n### Sub\n";
+        let items = compute_outline(text, "markdown");
+        assert_eq!(
+            items
+                .iter()
+                .map(|item| (item.name.as_str(), item.depth))
+                .collect::<Vec<_>>(),
+            vec![("Title", 0), ("Section", 1), ("Sub", 2)]
+        );
+    }
+
+    #[test]
+    fn unknown_syntax_yields_empty_outline() {
+        assert!(compute_outline("fn main() {}", "").is_empty());
+    }
+
+    #[test]
+    fn syntax_name_is_looked_up_from_syntax_system() {
+        // `SYNTAX_SYSTEM` reports syntect's display names (e.g. "Rust"), so
+        // compare case-insensitively rather than pinning their exact casing.
+        assert!(syntax_name_for_path(Path::new("main.rs")).eq_ignore_ascii_case("rust"));
+        assert!(syntax_name_for_path(Path::new("README.md")).eq_ignore_ascii_case("markdown"));
+        assert_eq!(syntax_name_for_path(Path::new("Makefile")), "");
+    }
+}
+
+pub fn outline_view<'a>(outline: &'a [OutlineItem]) -> Element<'a, Message> {
+    let mut list = column::with_capacity(outline.len()).spacing(2);
+    for item in outline.iter() {
+        list = list.push(
+            row![
+                Space::with_width(Length::Fixed((item.depth * 16) as f32)),
+                button(text(item.name.clone()))
+                    .width(Length::Fill)
+                    .on_press(Message::OutlineGoto(item.line)),
+            ],
+        );
+    }
+
+    scrollable(list).height(Length::Shrink).into()
+}
@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::io;
+
+use crate::Message;
+use cosmic::{
+    iced::{widget::row, Length},
+    widget::{button, container, text, text_input},
+    Element,
+};
+
+/// A location to read/write file data from: either the local filesystem or
+/// a remote host reached over SSH/SFTP.
+///
+/// SCAFFOLDING ONLY: `SshFileSystem` below never opens a network connection
+/// and every `FileSystem` method on it returns `Unsupported`. `Message::OpenRemote`
+/// surfaces that plainly in the "Open Remote..." dialog (see `RemotePrompt::status`)
+/// rather than silently doing nothing; nothing is inserted into `nav_model` or
+/// `tab_model` yet.
+///
+//TODO: `open_folder` (in main.rs) already lists directories through
+// `LocalFileSystem` below; `Tab::open`/`Tab::save` (in tab.rs) still call
+// `std::fs` directly, and `ProjectNode` (in project.rs) has no way to carry
+// a handle to the `FileSystem` its tree was built from. Once those two land,
+// and `SshFileSystem` is backed by a real SSH/SFTP client crate, `open_remote`
+// can build a nav tree instead of just reporting connection status.
+pub trait FileSystem {
+    fn read_dir(&self, path: &str) -> io::Result<Vec<RemoteEntry>>;
+    fn read_to_string(&self, path: &str) -> io::Result<String>;
+    fn write(&self, path: &str, contents: &str) -> io::Result<()>;
+}
+
+#[derive(Clone, Debug)]
+pub struct RemoteEntry {
+    pub path: String,
+    pub name: String,
+    pub is_dir: bool,
+}
+
+/// The local filesystem, used by `App::open_folder` to list every project
+/// opened without an `ssh://` prefix.
+pub struct LocalFileSystem;
+
+impl FileSystem for LocalFileSystem {
+    fn read_dir(&self, path: &str) -> io::Result<Vec<RemoteEntry>> {
+        let mut entries = Vec::new();
+        for entry_res in std::fs::read_dir(path)? {
+            let entry = entry_res?;
+            let entry_path = entry.path();
+            entries.push(RemoteEntry {
+                name: entry.file_name().to_string_lossy().to_string(),
+                is_dir: entry_path.is_dir(),
+                path: entry_path.to_string_lossy().to_string(),
+            });
+        }
+        Ok(entries)
+    }
+
+    fn read_to_string(&self, path: &str) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    fn write(&self, path: &str, contents: &str) -> io::Result<()> {
+        std::fs::write(path, contents)
+    }
+}
+
+/// `user@host` plus the remote root path, parsed from an `ssh://` URI such
+/// as `ssh://user@host/path/to/project`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SshTarget {
+    pub user: Option<String>,
+    pub host: String,
+    pub path: String,
+}
+
+impl SshTarget {
+    pub fn parse(uri: &str) -> Option<Self> {
+        let rest = uri.strip_prefix("ssh://")?;
+        let (authority, path) = rest.split_once('/')?;
+        let (user, host) = match authority.split_once('@') {
+            Some((user, host)) => (Some(user.to_string()), host.to_string()),
+            None => (None, authority.to_string()),
+        };
+        if host.is_empty() {
+            return None;
+        }
+        Some(Self {
+            user,
+            host,
+            path: format!("/{path}"),
+        })
+    }
+}
+
+/// The connection parameters for one `ssh://` target. It implements the
+/// `FileSystem` trait, but opens no SSH/SFTP session: every method returns
+/// an `Unsupported` error.
+//TODO: connect over an actual SSH/SFTP client crate (e.g. `ssh2`); this
+// currently just records the connection parameters.
+pub struct SshFileSystem {
+    pub target: SshTarget,
+}
+
+impl SshFileSystem {
+    pub fn connect(target: SshTarget) -> io::Result<Self> {
+        Ok(Self { target })
+    }
+}
+
+/// State for the "Open Remote..." dialog that collects an `ssh://` URI.
+///
+/// `status` reports the outcome of the most recent `Message::OpenRemote`
+/// attempt directly in the dialog, since this feature is scaffolding-only
+/// (see `SshFileSystem` above) and has no nav tree or tab to show success
+/// or failure through instead.
+#[derive(Clone, Debug, Default)]
+pub struct RemotePrompt {
+    pub value: String,
+    pub status: Option<String>,
+}
+
+pub fn remote_prompt_view<'a>(prompt: &'a RemotePrompt) -> Element<'a, Message> {
+    let mut content = row![
+        text("Open Remote (ssh://user@host/path):"),
+        text_input("ssh://user@host/path", &prompt.value)
+            .on_input(Message::OpenRemoteValue)
+            .on_submit(Message::OpenRemoteSubmit)
+            .width(Length::Fixed(320.0)),
+        button(text("Cancel")).on_press(Message::OpenRemoteCancel),
+    ]
+    .spacing(8)
+    .align_items(cosmic::iced::Alignment::Center);
+
+    if let Some(status) = &prompt.status {
+        content = content.push(text(status.clone()));
+    }
+
+    container(content)
+        .padding(8)
+        .style(cosmic::style::Container::Primary)
+        .into()
+}
+
+impl FileSystem for SshFileSystem {
+    fn read_dir(&self, _path: &str) -> io::Result<Vec<RemoteEntry>> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!(
+                "SFTP directory listing to {} is not implemented yet",
+                self.target.host
+            ),
+        ))
+    }
+
+    fn read_to_string(&self, _path: &str) -> io::Result<String> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("SFTP file reads from {} are not implemented yet", self.target.host),
+        ))
+    }
+
+    fn write(&self, _path: &str, _contents: &str) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("SFTP file writes to {} are not implemented yet", self.target.host),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_user_host_and_path() {
+        assert_eq!(
+            SshTarget::parse("ssh://user@example.com/home/user/project"),
+            Some(SshTarget {
+                user: Some("user".to_string()),
+                host: "example.com".to_string(),
+                path: "/home/user/project".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_host_without_user() {
+        assert_eq!(
+            SshTarget::parse("ssh://example.com/project"),
+            Some(SshTarget {
+                user: None,
+                host: "example.com".to_string(),
+                path: "/project".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_non_ssh_uris_and_empty_hosts() {
+        assert_eq!(SshTarget::parse("file:///home/user/project"), None);
+        assert_eq!(SshTarget::parse("ssh://@/project"), None);
+    }
+}
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::{fs, path::PathBuf};
+
+use crate::Message;
+use cosmic::{
+    app::{message, Command},
+    iced::{widget::column, Length},
+    widget::{button, checkbox, container, scrollable, text, text_input},
+    Element,
+};
+
+/// A single match found while scanning the project for `query`.
+#[derive(Clone, Debug)]
+pub struct SearchHit {
+    pub path: PathBuf,
+    pub line: usize,
+    pub col: usize,
+    pub text: String,
+}
+
+/// State for the project-wide text search panel.
+#[derive(Clone, Debug, Default)]
+pub struct SearchState {
+    pub query: String,
+    pub regex: bool,
+    pub case_sensitive: bool,
+    pub results: Vec<SearchHit>,
+    pub searching: bool,
+}
+
+impl SearchState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// A compiled query, built once per batch and reused across every line of
+/// every file in that batch, rather than recompiling a `Regex` per line.
+enum Matcher {
+    Regex(regex::Regex),
+    Plain { needle: String, case_sensitive: bool },
+}
+
+impl Matcher {
+    fn new(query: &str, regex: bool, case_sensitive: bool) -> Option<Self> {
+        if regex {
+            let pattern = if case_sensitive {
+                query.to_string()
+            } else {
+                format!("(?i){query}")
+            };
+            Some(Matcher::Regex(regex::Regex::new(&pattern).ok()?))
+        } else {
+            Some(Matcher::Plain {
+                needle: if case_sensitive {
+                    query.to_string()
+                } else {
+                    query.to_lowercase()
+                },
+                case_sensitive,
+            })
+        }
+    }
+
+    fn find_in(&self, line: &str) -> Option<usize> {
+        match self {
+            Matcher::Regex(re) => re.find(line).map(|m| m.start()),
+            Matcher::Plain {
+                needle,
+                case_sensitive,
+            } => {
+                if *case_sensitive {
+                    line.find(needle.as_str())
+                } else {
+                    line.to_lowercase().find(needle.as_str())
+                }
+            }
+        }
+    }
+}
+
+/// Scan a single file for matches, returning any hits.
+fn search_file(path: &PathBuf, matcher: &Matcher) -> Vec<SearchHit> {
+    let contents = match fs::read_to_string(path) {
+        Ok(ok) => ok,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut hits = Vec::new();
+    for (line_idx, line) in contents.lines().enumerate() {
+        if let Some(col) = matcher.find_in(line) {
+            hits.push(SearchHit {
+                path: path.clone(),
+                line: line_idx,
+                col,
+                text: line.to_string(),
+            });
+        }
+    }
+    hits
+}
+
+/// Walk one batch of up to `BATCH_SIZE` entries: scan any files in `batch`, and queue
+/// their children for the next batch. Runs off the UI thread via
+/// `Command::perform` and schedules itself again until `remaining` is empty,
+/// so results stream in incrementally instead of blocking on the full walk.
+async fn search_batch(
+    batch: Vec<PathBuf>,
+    mut remaining: Vec<PathBuf>,
+    query: String,
+    regex: bool,
+    case_sensitive: bool,
+) -> (Vec<SearchHit>, Vec<PathBuf>, String, bool, bool) {
+    let mut hits = Vec::new();
+    let matcher = Matcher::new(&query, regex, case_sensitive);
+
+    for path in batch {
+        if path.is_dir() {
+            if let Ok(read_dir) = fs::read_dir(&path) {
+                for entry_res in read_dir {
+                    if let Ok(entry) = entry_res {
+                        remaining.push(entry.path());
+                    }
+                }
+            }
+        } else if let Some(matcher) = &matcher {
+            hits.extend(search_file(&path, matcher));
+        }
+    }
+
+    (hits, remaining, query, regex, case_sensitive)
+}
+
+/// Number of filesystem entries scanned per `Command::perform` round trip.
+const BATCH_SIZE: usize = 32;
+
+pub fn search_command(
+    roots: Vec<PathBuf>,
+    query: String,
+    regex: bool,
+    case_sensitive: bool,
+) -> Command<Message> {
+    let mut remaining = roots;
+    let batch: Vec<PathBuf> = remaining
+        .drain(..remaining.len().min(BATCH_SIZE))
+        .collect();
+
+    Command::perform(
+        search_batch(batch, remaining, query, regex, case_sensitive),
+        |(hits, remaining, query, regex, case_sensitive)| {
+            message::app(Message::SearchBatch {
+                hits,
+                remaining,
+                query,
+                regex,
+                case_sensitive,
+            })
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_match_is_case_insensitive_by_default() {
+        let matcher = Matcher::new("foo", false, false).unwrap();
+        assert_eq!(matcher.find_in("a FOO b"), Some(2));
+    }
+
+    #[test]
+    fn plain_match_respects_case_sensitivity() {
+        let matcher = Matcher::new("foo", false, true).unwrap();
+        assert_eq!(matcher.find_in("a FOO b"), None);
+        assert_eq!(matcher.find_in("a foo b"), Some(2));
+    }
+
+    #[test]
+    fn regex_match_is_compiled_once_and_reused() {
+        let matcher = Matcher::new(r"\d+", true, true).unwrap();
+        assert_eq!(matcher.find_in("line 1"), Some(5));
+        assert_eq!(matcher.find_in("line 22"), Some(5));
+    }
+
+    #[test]
+    fn invalid_regex_query_yields_no_matcher() {
+        assert!(Matcher::new("[", true, true).is_none());
+    }
+}
+
+pub fn search_view<'a>(search: &'a SearchState) -> Element<'a, Message> {
+    let mut results = column::with_capacity(search.results.len()).spacing(2);
+    for hit in search.results.iter() {
+        let label = format!(
+            "{}:{}: {}",
+            hit.path.to_string_lossy(),
+            hit.line + 1,
+            hit.text.trim()
+        );
+        results = results.push(button(text(label)).width(Length::Fill).on_press(
+            Message::SearchGoto {
+                path: hit.path.clone(),
+                line: hit.line,
+                col: hit.col,
+            },
+        ));
+    }
+
+    let query = search.query.clone();
+    let regex = search.regex;
+    let case_sensitive = search.case_sensitive;
+    let options = cosmic::iced::widget::row![
+        checkbox("Regex", search.regex, {
+            let query = query.clone();
+            move |regex| Message::SearchQuery {
+                query: query.clone(),
+                regex,
+                case_sensitive,
+            }
+        }),
+        checkbox("Case sensitive", search.case_sensitive, move |case_sensitive| {
+            Message::SearchQuery {
+                query: query.clone(),
+                regex,
+                case_sensitive,
+            }
+        }),
+    ]
+    .spacing(16);
+
+    let content = column::with_capacity(3)
+        .spacing(8)
+        .push(
+            text_input("Search project...", &search.query)
+                .on_input(|query| Message::SearchQuery {
+                    query,
+                    regex: search.regex,
+                    case_sensitive: search.case_sensitive,
+                })
+                .width(Length::Fill),
+        )
+        .push(options)
+        .push(scrollable(results).height(Length::Shrink));
+
+    container(content)
+        .padding(16)
+        .width(Length::Fixed(560.0))
+        .style(cosmic::style::Container::Primary)
+        .into()
+}
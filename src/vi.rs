@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::path::PathBuf;
+
+/// An Ex command parsed from the Vi command line (the part after `:`).
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExCommand {
+    Write,
+    Quit { force: bool },
+    WriteQuit,
+    Edit(PathBuf),
+    GotoLine(usize),
+    GotoEnd,
+    Substitute {
+        start_line: usize,
+        end_line: usize,
+        pattern: String,
+        replacement: String,
+        global: bool,
+    },
+    Unknown(String),
+}
+
+/// Parse a submitted Ex command such as `w`, `q!`, `wq`, `e foo.rs`, a bare
+/// line number, `$`, or `:s/pat/rep/[g]` (optionally `%`- or range-prefixed).
+///
+/// `current_line` and `last_line` are zero-indexed and used to resolve a
+/// substitution range when none is given.
+pub fn parse_ex_command(input: &str, current_line: usize, last_line: usize) -> ExCommand {
+    let input = input.trim();
+
+    match input {
+        "w" => return ExCommand::Write,
+        "q" => return ExCommand::Quit { force: false },
+        "q!" => return ExCommand::Quit { force: true },
+        "wq" | "x" => return ExCommand::WriteQuit,
+        "$" => return ExCommand::GotoEnd,
+        _ => {}
+    }
+
+    if let Some(rest) = input.strip_prefix("e ") {
+        return ExCommand::Edit(PathBuf::from(rest.trim()));
+    }
+
+    if let Ok(line) = input.parse::<usize>() {
+        return ExCommand::GotoLine(line.saturating_sub(1));
+    }
+
+    if let Some(sub) = parse_substitute(input, current_line, last_line) {
+        return sub;
+    }
+
+    ExCommand::Unknown(input.to_string())
+}
+
+fn parse_substitute(input: &str, current_line: usize, last_line: usize) -> Option<ExCommand> {
+    let s_idx = input.find('s')?;
+    let (range_part, command_part) = (&input[..s_idx], &input[s_idx..]);
+    let command_part = command_part.strip_prefix("s/")?;
+
+    let (start_line, end_line) = match range_part {
+        "" => (current_line, current_line),
+        "%" => (0, last_line),
+        other => {
+            let mut parts = other.splitn(2, ',');
+            let start = parts.next()?.parse::<usize>().ok()?.saturating_sub(1);
+            let end = match parts.next() {
+                Some(s) => s.parse::<usize>().ok()?.saturating_sub(1),
+                None => start,
+            };
+            (start, end)
+        }
+    };
+
+    let mut fields = command_part.splitn(3, '/');
+    let pattern = fields.next()?.to_string();
+    let replacement = fields.next()?.to_string();
+    let flags = fields.next().unwrap_or("");
+
+    Some(ExCommand::Substitute {
+        start_line,
+        end_line,
+        pattern,
+        replacement,
+        global: flags.contains('g'),
+    })
+}
+
+/// Apply a `:s` substitution to `text`, returning the new text, or `None`
+/// if `pattern` fails to compile as a regex.
+pub fn apply_substitute(
+    text: &str,
+    start_line: usize,
+    end_line: usize,
+    pattern: &str,
+    replacement: &str,
+    global: bool,
+) -> Option<String> {
+    let re = regex::Regex::new(pattern).ok()?;
+    let had_trailing_newline = text.ends_with('\n');
+
+    let mut out = String::with_capacity(text.len());
+    for (line_idx, line) in text.lines().enumerate() {
+        if line_idx >= start_line && line_idx <= end_line {
+            if global {
+                out.push_str(&re.replace_all(line, replacement));
+            } else {
+                out.push_str(&re.replace(line, replacement));
+            }
+        } else {
+            out.push_str(line);
+        }
+        out.push('\n');
+    }
+
+    if !had_trailing_newline {
+        out.pop();
+    }
+
+    Some(out)
+}
+
+/// Round `index` up to the nearest char boundary in `s`, so it can be used
+/// as a slice bound without panicking even if it lands inside a multi-byte
+/// character.
+fn ceil_char_boundary(s: &str, index: usize) -> usize {
+    let mut idx = index.min(s.len());
+    while idx < s.len() && !s.is_char_boundary(idx) {
+        idx += 1;
+    }
+    idx
+}
+
+/// Round `index` down to the nearest char boundary in `s`, so it can be used
+/// as a slice bound without panicking even if it lands inside a multi-byte
+/// character.
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut idx = index.min(s.len());
+    while idx > 0 && !s.is_char_boundary(idx) {
+        idx -= 1;
+    }
+    idx
+}
+
+/// Find the next occurrence of `pattern` in `text`, searching line-by-line
+/// starting just after `(from_line, from_col)` and wrapping around the
+/// buffer. Returns the `(line, col)` of the match start.
+pub fn find_match(
+    text: &str,
+    pattern: &str,
+    from_line: usize,
+    from_col: usize,
+    forwards: bool,
+) -> Option<(usize, usize)> {
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.is_empty() {
+        return None;
+    }
+    let line_count = lines.len();
+
+    if forwards {
+        for step in 0..=line_count {
+            let line_idx = (from_line + step) % line_count;
+            let line = lines[line_idx];
+            let search_from = if step == 0 && line_idx == from_line {
+                ceil_char_boundary(line, from_col + 1)
+            } else {
+                0
+            };
+            if search_from <= line.len() {
+                if let Some(col) = line[search_from..].find(pattern) {
+                    return Some((line_idx, search_from + col));
+                }
+            }
+        }
+    } else {
+        for step in 0..=line_count {
+            let line_idx = (from_line + line_count - step) % line_count;
+            let line = lines[line_idx];
+            let search_end = if step == 0 && line_idx == from_line {
+                floor_char_boundary(line, from_col)
+            } else {
+                line.len()
+            };
+            if let Some(col) = line[..search_end].rfind(pattern) {
+                return Some((line_idx, col));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_simple_ex_commands() {
+        assert_eq!(parse_ex_command("w", 0, 0), ExCommand::Write);
+        assert_eq!(parse_ex_command("q!", 0, 0), ExCommand::Quit { force: true });
+        assert_eq!(parse_ex_command("wq", 0, 0), ExCommand::WriteQuit);
+        assert_eq!(
+            parse_ex_command("e foo.rs", 0, 0),
+            ExCommand::Edit(PathBuf::from("foo.rs"))
+        );
+        assert_eq!(parse_ex_command("12", 0, 0), ExCommand::GotoLine(11));
+        assert_eq!(parse_ex_command("$", 0, 0), ExCommand::GotoEnd);
+    }
+
+    #[test]
+    fn parses_substitute_with_implicit_and_explicit_ranges() {
+        assert_eq!(
+            parse_ex_command("s/foo/bar/", 4, 10),
+            ExCommand::Substitute {
+                start_line: 4,
+                end_line: 4,
+                pattern: "foo".to_string(),
+                replacement: "bar".to_string(),
+                global: false,
+            }
+        );
+        assert_eq!(
+            parse_ex_command("%s/foo/bar/g", 4, 10),
+            ExCommand::Substitute {
+                start_line: 0,
+                end_line: 10,
+                pattern: "foo".to_string(),
+                replacement: "bar".to_string(),
+                global: true,
+            }
+        );
+    }
+
+    #[test]
+    fn apply_substitute_replaces_within_range_only() {
+        let text = "foo\nfoo\nfoo\n";
+        let out = apply_substitute(text, 1, 1, "foo", "bar", false).unwrap();
+        assert_eq!(out, "foo\nbar\nfoo\n");
+    }
+
+    #[test]
+    fn find_match_wraps_around_and_finds_next_hit() {
+        let text = "alpha\nbeta\nalpha\n";
+        assert_eq!(find_match(text, "alpha", 0, 0, true), Some((2, 0)));
+        assert_eq!(find_match(text, "alpha", 2, 0, false), Some((0, 0)));
+    }
+
+    #[test]
+    fn find_match_does_not_panic_on_multibyte_from_col() {
+        // 'é' occupies bytes 1..3, so `from_col + 1 == 2` (forward) and
+        // `from_col == 2` (backward) both land mid-character.
+        let text = "héllo world\n";
+        assert_eq!(find_match(text, "world", 0, 1, true), Some((0, 7)));
+        assert_eq!(find_match(text, "h", 0, 2, false), Some((0, 0)));
+    }
+}